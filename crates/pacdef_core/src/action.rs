@@ -0,0 +1,20 @@
+//! Names of the subcommands accepted on the command line.
+//!
+//! These are kept as plain `&str` constants (instead of an enum) so that
+//! [`crate::args`] can name its subcommands with them and
+//! [`crate::core::Pacdef::run_action_from_arg`] can match them against
+//! [`clap::ArgMatches::subcommand`] without duplicating the literal strings.
+
+pub const CLEAN: &str = "clean";
+pub const EDIT: &str = "edit";
+pub const GROUPS: &str = "groups";
+pub const IMPORT: &str = "import";
+pub const NEW: &str = "new";
+pub const REMOVE: &str = "remove";
+pub const REVIEW: &str = "review";
+pub const SHOW: &str = "show";
+pub const SEARCH: &str = "search";
+pub const SYNC: &str = "sync";
+pub const UNMANAGED: &str = "unmanaged";
+pub const VERSION: &str = "version";
+pub const COMPLETION: &str = "completion";
@@ -0,0 +1,147 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use clap_complete::Shell;
+
+use crate::action::*;
+
+/// Machine-readable format for the `--format` flag on `sync`, `clean`, and `unmanaged`.
+///
+/// Requesting a format skips [`crate::ui::get_user_confirmation`] and the human-oriented
+/// [`crate::backend::ToDoPerBackend::show`] output in favor of a serialized plan, so the
+/// result can be piped into other tools or reviewed in automation.
+#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+pub enum Format {
+    Json,
+    Ron,
+}
+
+/// Builds the [`clap::Command`] that describes all of pacdef's subcommands and
+/// arguments.
+///
+/// This is split out from [`get`] so that other consumers ([`generate_completions`]
+/// and alias expansion in [`crate::core::Pacdef::run_action_from_arg`]) can
+/// introspect or re-parse against the command tree - e.g. to walk its
+/// subcommands when generating shell completions - without having to parse
+/// the live process arguments.
+#[must_use]
+pub fn command() -> Command {
+    // Group names are not completed dynamically: `completion` (below) renders a static
+    // script via `clap_complete::generate`, which has no way to call back into
+    // `get_pacdef_group_dir` at completion time. Doing so would require switching
+    // `completion` to clap_complete's dynamic engine (`CompleteEnv`), which shells source
+    // as a callback into this binary instead of a pregenerated script - a bigger change
+    // than this argument needs today.
+    let group_arg = || Arg::new("group").required(true).num_args(1..);
+    let format_arg = || {
+        Arg::new("format")
+            .long("format")
+            .value_parser(value_parser!(Format))
+            .help("print the plan in a machine-readable format instead of applying it")
+    };
+
+    Command::new("pacdef")
+        .about("declarative, cross-platform package manager wrapper")
+        .version(crate::core::get_version_string())
+        .subcommand_required(true)
+        .allow_external_subcommands(true)
+        .arg(
+            Arg::new("noconfirm")
+                .long("noconfirm")
+                .global(true)
+                .action(ArgAction::SetTrue)
+                .help("do not ask for confirmation before installing or removing packages"),
+        )
+        .subcommand(
+            Command::new(CLEAN)
+                .about("remove unmanaged packages")
+                .arg(format_arg()),
+        )
+        .subcommand(
+            Command::new(EDIT)
+                .about("edit one or more group files")
+                .arg(group_arg()),
+        )
+        .subcommand(Command::new(GROUPS).about("show names of imported groups"))
+        .subcommand(
+            Command::new(IMPORT)
+                .about("import one or more group files")
+                .arg(
+                    Arg::new("files")
+                        .required(true)
+                        .num_args(1..)
+                        .value_parser(value_parser!(PathBuf)),
+                ),
+        )
+        .subcommand(
+            Command::new(NEW)
+                .about("create one or more new group files")
+                .arg(Arg::new("groups").required(true).num_args(1..))
+                .arg(
+                    Arg::new("edit")
+                        .short('e')
+                        .long("edit")
+                        .action(ArgAction::SetTrue)
+                        .help("open an editor for the new group files"),
+                ),
+        )
+        .subcommand(
+            Command::new(REMOVE)
+                .about("remove one or more group files")
+                .arg(Arg::new("groups").required(true).num_args(1..)),
+        )
+        .subcommand(Command::new(REVIEW).about("review unmanaged packages"))
+        .subcommand(
+            Command::new(SHOW)
+                .about("show packages under one or more groups")
+                .arg(group_arg()),
+        )
+        .subcommand(
+            Command::new(SEARCH)
+                .about("search for a package under all imported groups")
+                .arg(Arg::new("package").required(true)),
+        )
+        .subcommand(
+            Command::new(SYNC)
+                .about("install all missing packages")
+                .arg(format_arg()),
+        )
+        .subcommand(
+            Command::new(UNMANAGED)
+                .about("show explicitly installed packages that are not managed by pacdef")
+                .arg(format_arg()),
+        )
+        .subcommand(Command::new(VERSION).about("show version information"))
+        .subcommand(
+            Command::new(COMPLETION)
+                .about("generate a shell completion script")
+                .arg(
+                    Arg::new("shell")
+                        .required(true)
+                        .value_parser(value_parser!(Shell)),
+                ),
+        )
+}
+
+/// Parses the process arguments into [`ArgMatches`] using the [`command`] definition.
+#[must_use]
+pub fn get() -> ArgMatches {
+    command().get_matches()
+}
+
+pub fn get_absolutized_file_paths(args: &ArgMatches) -> Result<Vec<PathBuf>> {
+    args.get_many::<PathBuf>("files")
+        .context("getting files from args")?
+        .map(|file| {
+            std::path::absolute(file).with_context(|| format!("absolutizing {}", file.display()))
+        })
+        .collect()
+}
+
+/// Renders the completion script for `shell` to stdout.
+pub fn generate_completions(shell: Shell) {
+    let mut cmd = command();
+    let name = cmd.get_name().to_string();
+    clap_complete::generate(shell, &mut cmd, name, &mut std::io::stdout());
+}
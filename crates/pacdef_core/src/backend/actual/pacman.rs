@@ -0,0 +1,109 @@
+use std::collections::HashSet;
+use std::process::Command;
+
+use anyhow::Context;
+
+use crate::backend::backend_trait::{Backend, Switches, Text};
+use crate::backend::macros::impl_backend_constants;
+use crate::Package;
+
+#[derive(Debug, Clone)]
+pub struct Pacman {
+    pub(crate) binary: String,
+    pub(crate) aur_rm_args: Option<Vec<String>>,
+    pub(crate) packages: HashSet<Package>,
+}
+
+const SECTION: Text = "pacman";
+
+const SWITCHES_INSTALL: Switches = &["-S", "--needed"];
+const SWITCHES_MAKE_DEPENDENCY: Switches = &["-D", "--asdeps"];
+const SWITCHES_NOCONFIRM: Switches = &["--noconfirm"];
+const SWITCHES_REMOVE: Switches = &["-Rs"];
+
+const SUPPORTS_AS_DEPENDENCY: bool = true;
+
+impl Backend for Pacman {
+    impl_backend_constants!();
+
+    fn get_binary(&self) -> &str {
+        &self.binary
+    }
+
+    fn get_all_installed_packages(&self) -> anyhow::Result<HashSet<Package>> {
+        let output = Command::new(self.get_binary())
+            .args(["-Qq"])
+            .output()
+            .context("listing installed pacman packages")?
+            .stdout;
+        Ok(String::from_utf8(output)?
+            .lines()
+            .map(Package::from)
+            .collect())
+    }
+
+    fn get_explicitly_installed_packages(&self) -> anyhow::Result<HashSet<Package>> {
+        let output = Command::new(self.get_binary())
+            .args(["-Qqe"])
+            .output()
+            .context("listing explicitly installed pacman packages")?
+            .stdout;
+        Ok(String::from_utf8(output)?
+            .lines()
+            .map(Package::from)
+            .collect())
+    }
+
+    fn make_dependency(&self, packages: &[Package]) -> anyhow::Result<std::process::ExitStatus> {
+        anyhow::ensure!(
+            SUPPORTS_AS_DEPENDENCY,
+            "{} does not support marking packages as a dependency",
+            self.get_binary()
+        );
+        let mut cmd = Command::new(self.get_binary());
+        cmd.args(SWITCHES_MAKE_DEPENDENCY);
+        cmd.args(packages.iter().map(|p| p.name.as_str()));
+        Ok(cmd.status()?)
+    }
+
+    fn install_packages(
+        &self,
+        packages: &[Package],
+        noconfirm: bool,
+    ) -> anyhow::Result<std::process::ExitStatus> {
+        let mut cmd = Command::new(self.get_binary());
+        cmd.args(SWITCHES_INSTALL);
+        if noconfirm {
+            cmd.args(SWITCHES_NOCONFIRM);
+        }
+        cmd.args(packages.iter().map(|p| p.name.as_str()));
+        Ok(cmd.status()?)
+    }
+
+    fn remove_packages(
+        &self,
+        packages: &[Package],
+        noconfirm: bool,
+    ) -> anyhow::Result<std::process::ExitStatus> {
+        let mut cmd = Command::new(self.get_binary());
+        cmd.args(SWITCHES_REMOVE);
+        if let Some(extra) = &self.aur_rm_args {
+            cmd.args(extra);
+        }
+        if noconfirm {
+            cmd.args(SWITCHES_NOCONFIRM);
+        }
+        cmd.args(packages.iter().map(|p| p.name.as_str()));
+        Ok(cmd.status()?)
+    }
+}
+
+impl Pacman {
+    pub(crate) fn new(binary: String) -> Self {
+        Self {
+            binary,
+            aur_rm_args: None,
+            packages: HashSet::new(),
+        }
+    }
+}
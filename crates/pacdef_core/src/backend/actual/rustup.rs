@@ -1,7 +1,7 @@
 use crate::backend::backend_trait::{Backend, Switches, Text};
 use crate::backend::macros::impl_backend_constants;
 use crate::{Group, Package};
-use anyhow::Context;
+use anyhow::{ensure, Context};
 use core::panic;
 use std::collections::HashSet;
 use std::os::unix::process::ExitStatusExt;
@@ -26,6 +26,10 @@ const SUPPORTS_AS_DEPENDENCY: bool = false;
 impl Backend for Rustup {
     impl_backend_constants!();
 
+    fn get_binary(&self) -> &str {
+        BINARY
+    }
+
     fn get_all_installed_packages(&self) -> anyhow::Result<HashSet<Package>> {
         let mut toolchains_vec = self
             .run_toolchain_command(&[&"toolchain", &"list"])
@@ -36,7 +40,7 @@ impl Backend for Rustup {
             .map(|name| ["toolchain", name].join("/").into())
             .collect();
 
-        let packages: HashSet<Package> = self
+        let components: HashSet<Package> = self
             .run_component_command(
                 &[&"component", &"list", &"--installed", &"--toolchain"],
                 &mut toolchains_vec,
@@ -45,7 +49,19 @@ impl Backend for Rustup {
             .iter()
             .map(|name| ["component", name].join("/").into())
             .collect();
-        toolchains.extend(packages.into_iter());
+        toolchains.extend(components);
+
+        let targets: HashSet<Package> = self
+            .run_target_command(
+                &[&"target", &"list", &"--installed", &"--toolchain"],
+                &mut toolchains_vec,
+            )
+            .context("Getting installed targets")?
+            .iter()
+            .map(|name| ["target", name].join("/").into())
+            .collect();
+        toolchains.extend(targets);
+
         Ok(toolchains)
     }
 
@@ -99,8 +115,63 @@ impl Backend for Rustup {
                 }
             }
         }
+        for p in packages {
+            let repo = p
+                .repo
+                .as_ref()
+                .expect("Not specified whether it is a target, component or toolchain!");
+            if repo == "target" {
+                let mut iter = p.name.split('/');
+                let toolchain = iter.next().expect("Toolchain not specified!");
+                let target = iter.next().expect("Target triple not specified!");
+                let mut cmd = Command::new(self.get_binary());
+                cmd.args(&[&"target", &"add"]);
+                cmd.args([&"--toolchain", format!("{toolchain}").as_str()]);
+                cmd.arg(format!("{target}"));
+                result = cmd.status().context("Installing target {p}");
+                if !result.as_ref().is_ok_and(|exit| exit.success()) {
+                    return result;
+                }
+            }
+        }
         result
     }
+
+    fn remove_packages(
+        &self,
+        packages: &[Package],
+        noconfirm: bool,
+    ) -> anyhow::Result<std::process::ExitStatus> {
+        let (targets, rest): (Vec<_>, Vec<_>) = packages
+            .iter()
+            .partition(|p| p.repo.as_deref() == Some("target"));
+
+        for p in &targets {
+            let mut iter = p.name.split('/');
+            let toolchain = iter.next().expect("Toolchain not specified!");
+            let target = iter.next().expect("Target triple not specified!");
+            let mut cmd = Command::new(self.get_binary());
+            cmd.args(&[&"target", &"remove"]);
+            cmd.args([&"--toolchain", toolchain]);
+            cmd.arg(target);
+            let status = cmd.status().context("Removing target {p}")?;
+            if !status.success() {
+                return Ok(status);
+            }
+        }
+
+        if rest.is_empty() {
+            return Ok(std::process::ExitStatus::from_raw(0));
+        }
+
+        let mut cmd = Command::new(self.get_binary());
+        cmd.args(SWITCHES_REMOVE);
+        if noconfirm {
+            cmd.args(SWITCHES_NOCONFIRM);
+        }
+        cmd.args(rest.iter().map(|p| p.name.as_str()));
+        Ok(cmd.status()?)
+    }
 }
 
 impl Rustup {
@@ -110,6 +181,22 @@ impl Rustup {
         }
     }
 
+    /// Lists every target triple rustup knows about for `toolchain`, installed or not.
+    ///
+    /// Used by [`Self::run_component_command`] to recognize and strip the target-triple
+    /// suffix that `rustup component list` appends to most (but not all) component
+    /// names, instead of matching against a hardcoded set of component names.
+    fn get_known_target_triples(&self, toolchain: &str) -> Result<Vec<String>, anyhow::Error> {
+        let mut cmd = Command::new(self.get_binary());
+        cmd.args(&["target", "list", "--toolchain", toolchain]);
+        let output = String::from_utf8(cmd.output()?.stdout)?;
+        Ok(output
+            .lines()
+            .filter_map(|line| line.split_whitespace().next())
+            .map(String::from)
+            .collect())
+    }
+
     fn run_component_command(
         &self,
         args: &[&str],
@@ -117,26 +204,35 @@ impl Rustup {
     ) -> Result<Vec<String>, anyhow::Error> {
         let mut val = Vec::new();
         for toolchain in toolchains {
+            let triples = self
+                .get_known_target_triples(toolchain)
+                .context("Getting known target triples")?;
+
             let mut cmd = Command::new(self.get_binary());
             cmd.args(args);
             cmd.arg(&toolchain);
             let output = String::from_utf8(cmd.output()?.stdout)?;
-            for i in output.lines() {
-                let mut it = i.splitn(3, "-");
-                let component = it.next().expect("Component name is empty!");
-                match component {
-                    "cargo" | "rustfmt" | "clippy" | "miri" | "rls" | "rustc" => {
-                        val.push([toolchain, component].join("/"));
-                    }
-                    _ => {
-                        let component = [
-                            component,
-                            it.next().expect("No such component is managed by rustup"),
-                        ]
-                        .join("-");
-                        val.push([toolchain, component.as_str()].join("/"));
-                    }
-                }
+            for line in output.lines() {
+                let entry = line.split_whitespace().next().unwrap_or(line);
+                ensure!(
+                    !entry.is_empty(),
+                    "could not parse component entry for toolchain {toolchain}"
+                );
+
+                let component = triples
+                    .iter()
+                    .find_map(|triple| {
+                        entry
+                            .strip_suffix(triple.as_str())
+                            .and_then(|rest| rest.strip_suffix('-'))
+                    })
+                    .unwrap_or(entry);
+                ensure!(
+                    !component.is_empty(),
+                    "could not parse component name from {entry}"
+                );
+
+                val.push([toolchain.as_str(), component].join("/"));
             }
         }
         Ok(val)
@@ -152,4 +248,22 @@ impl Rustup {
         }
         Ok(val)
     }
+
+    fn run_target_command(
+        &self,
+        args: &[&str],
+        toolchains: &mut Vec<String>,
+    ) -> Result<Vec<String>, anyhow::Error> {
+        let mut val = Vec::new();
+        for toolchain in toolchains {
+            let mut cmd = Command::new(self.get_binary());
+            cmd.args(args);
+            cmd.arg(&toolchain);
+            let output = String::from_utf8(cmd.output()?.stdout)?;
+            for triple in output.lines() {
+                val.push([toolchain.as_str(), triple].join("/"));
+            }
+        }
+        Ok(val)
+    }
 }
@@ -0,0 +1,55 @@
+use std::collections::HashSet;
+use std::process::ExitStatus;
+
+use anyhow::Result;
+
+use crate::{Group, Package};
+
+/// A `const` holding a single program name or subcommand switch, e.g. `"rustup"`.
+pub type Text = &'static str;
+
+/// A `const` holding a sequence of CLI switches passed to a backend's binary, e.g.
+/// `&["component", "add"]`.
+pub type Switches = &'static [&'static str];
+
+/// A package manager pacdef can declaratively sync group files against.
+///
+/// Implementors are almost always built via [`crate::backend::macros::impl_backend_constants`],
+/// which derives the trivial methods (`get_section`, `load`, the diffing methods) from a
+/// `SECTION` const and a `packages: HashSet<Package>` field, and implement `get_binary` and the
+/// methods that need backend-specific behavior themselves.
+pub trait Backend: Send {
+    /// The name under which packages for this backend are grouped in group files, e.g. `"rustup"`.
+    fn get_section(&self) -> &str;
+
+    /// The binary invoked to query or mutate this backend, e.g. `"rustup"` or the configured AUR helper.
+    fn get_binary(&self) -> &str;
+
+    /// Populates `self`'s managed package set from the group files that declare packages for
+    /// this backend's section.
+    fn load(&mut self, groups: &HashSet<Group>);
+
+    /// Every package the backend considers installed, regardless of how it got there.
+    fn get_all_installed_packages(&self) -> Result<HashSet<Package>>;
+
+    /// Every package the backend considers explicitly (not transitively) installed.
+    fn get_explicitly_installed_packages(&self) -> Result<HashSet<Package>>;
+
+    /// Packages declared in group files but not currently installed, sorted for stable output.
+    fn get_missing_packages_sorted(&self) -> Result<Vec<Package>>;
+
+    /// Explicitly installed packages that are not declared in any group file, sorted for stable output.
+    fn get_unmanaged_packages_sorted(&self) -> Result<Vec<Package>>;
+
+    /// Marks `packages` as installed as a dependency, so the backend stops treating them as
+    /// explicitly installed. Not every backend supports this.
+    fn make_dependency(&self, packages: &[Package]) -> Result<ExitStatus>;
+
+    /// Installs `packages`. When `noconfirm` is set, the backend's own no-confirmation switch
+    /// (if any) is passed through to the underlying package manager.
+    fn install_packages(&self, packages: &[Package], noconfirm: bool) -> Result<ExitStatus>;
+
+    /// Removes `packages`. When `noconfirm` is set, the backend's own no-confirmation switch
+    /// (if any) is passed through to the underlying package manager.
+    fn remove_packages(&self, packages: &[Package], noconfirm: bool) -> Result<ExitStatus>;
+}
@@ -0,0 +1,46 @@
+/// Implements the parts of [`crate::backend::backend_trait::Backend`] that are the same for
+/// every backend built around a `packages: HashSet<Package>` field and a `SECTION` const:
+/// the section getter, loading managed packages from group files, and diffing them against
+/// the backend's own installed-package queries.
+///
+/// `get_binary` is deliberately not included here: Pacman's binary is configurable at
+/// runtime (a plain `&self.binary` field), while Rustup's is a fixed `const BINARY`, so each
+/// backend implements that getter itself.
+///
+/// Methods whose shape differs per backend (`make_dependency`, `install_packages`,
+/// `remove_packages`) are also left for each backend to implement itself.
+macro_rules! impl_backend_constants {
+    () => {
+        fn get_section(&self) -> &str {
+            SECTION
+        }
+
+        fn load(&mut self, groups: &std::collections::HashSet<crate::Group>) {
+            self.packages = groups
+                .iter()
+                .flat_map(|group| group.packages.iter())
+                .filter(|package| package.section() == SECTION)
+                .cloned()
+                .collect();
+        }
+
+        fn get_missing_packages_sorted(&self) -> anyhow::Result<Vec<crate::Package>> {
+            let installed = self.get_all_installed_packages()?;
+            let mut missing: Vec<_> = self.packages.difference(&installed).cloned().collect();
+            missing.sort_unstable();
+            Ok(missing)
+        }
+
+        fn get_unmanaged_packages_sorted(&self) -> anyhow::Result<Vec<crate::Package>> {
+            let explicitly_installed = self.get_explicitly_installed_packages()?;
+            let mut unmanaged: Vec<_> = explicitly_installed
+                .difference(&self.packages)
+                .cloned()
+                .collect();
+            unmanaged.sort_unstable();
+            Ok(unmanaged)
+        }
+    };
+}
+
+pub(crate) use impl_backend_constants;
@@ -0,0 +1,137 @@
+pub mod actual;
+pub mod backend_trait;
+pub(crate) mod macros;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+pub use actual::pacman::Pacman;
+pub use actual::rustup::Rustup;
+pub use backend_trait::Backend;
+
+use crate::args::Format;
+use crate::Package;
+
+/// The subset of a [`ToDoPerBackend`] entry that is meaningful to an external tool: the
+/// backend's section name and the packages it would install or remove. Unlike `Backend`
+/// itself, this is plain data, so it can derive [`Serialize`] directly.
+#[derive(Serialize)]
+struct BackendPlan {
+    section: String,
+    packages: Vec<String>,
+}
+
+/// The backends pacdef knows how to drive.
+pub enum Backends {
+    Pacman,
+    Rustup,
+}
+
+impl Backends {
+    /// Returns a freshly constructed instance of every known backend, in a stable order.
+    pub fn iter() -> impl Iterator<Item = Box<dyn Backend>> {
+        [
+            Box::new(Pacman::new("pacman".to_string())) as Box<dyn Backend>,
+            Box::new(Rustup::new()) as Box<dyn Backend>,
+        ]
+        .into_iter()
+    }
+}
+
+/// The packages each backend should install or remove, one entry per backend, collected by
+/// [`crate::core::Pacdef::query_backends_parallel`].
+pub struct ToDoPerBackend(Vec<(Box<dyn Backend>, Vec<Package>)>);
+
+impl ToDoPerBackend {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    pub fn push(&mut self, entry: (Box<dyn Backend>, Vec<Package>)) {
+        self.0.push(entry);
+    }
+
+    #[must_use]
+    pub fn nothing_to_do_for_all_backends(&self) -> bool {
+        self.0.iter().all(|(_, diff)| diff.is_empty())
+    }
+
+    pub fn show(&self) -> Result<()> {
+        for (backend, diff) in &self.0 {
+            if diff.is_empty() {
+                continue;
+            }
+            println!("{}:", backend.get_section());
+            for package in diff {
+                println!("    {package}");
+            }
+        }
+        Ok(())
+    }
+
+    /// Installs the missing packages of every backend that has any.
+    ///
+    /// `noconfirm` is forwarded to [`Backend::install_packages`] so each backend can pass its
+    /// own no-confirmation switch through to the underlying package manager.
+    pub fn install_missing_packages(self, noconfirm: bool) -> Result<()> {
+        for (backend, diff) in self.0 {
+            if diff.is_empty() {
+                continue;
+            }
+            let section = backend.get_section().to_string();
+            let status = backend
+                .install_packages(&diff, noconfirm)
+                .with_context(|| format!("installing packages for backend '{section}'"))?;
+            anyhow::ensure!(status.success(), "backend '{section}' exited with an error");
+        }
+        Ok(())
+    }
+
+    /// Removes the unmanaged packages of every backend that has any.
+    ///
+    /// `noconfirm` is forwarded to [`Backend::remove_packages`] so each backend can pass its
+    /// own no-confirmation switch through to the underlying package manager.
+    pub fn remove_unmanaged_packages(self, noconfirm: bool) -> Result<()> {
+        for (backend, diff) in self.0 {
+            if diff.is_empty() {
+                continue;
+            }
+            let section = backend.get_section().to_string();
+            let status = backend
+                .remove_packages(&diff, noconfirm)
+                .with_context(|| format!("removing packages for backend '{section}'"))?;
+            anyhow::ensure!(status.success(), "backend '{section}' exited with an error");
+        }
+        Ok(())
+    }
+
+    /// Renders the plan as machine-readable output in the given `format`, instead of the
+    /// human-oriented listing produced by [`Self::show`].
+    pub fn export(&self, format: Format) -> Result<()> {
+        let plan: Vec<BackendPlan> = self
+            .0
+            .iter()
+            .map(|(backend, diff)| BackendPlan {
+                section: backend.get_section().to_string(),
+                packages: diff.iter().map(ToString::to_string).collect(),
+            })
+            .collect();
+
+        let rendered = match format {
+            Format::Json => {
+                serde_json::to_string_pretty(&plan).context("serializing plan as JSON")?
+            }
+            Format::Ron => ron::ser::to_string_pretty(&plan, ron::ser::PrettyConfig::default())
+                .context("serializing plan as RON")?,
+        };
+        println!("{rendered}");
+        Ok(())
+    }
+}
+
+impl Default for ToDoPerBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,56 @@
+//! User-configurable settings, loaded from `$XDG_CONFIG_HOME/pacdef/pacdef.toml`.
+
+use std::collections::HashMap;
+use std::fs::read_to_string;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::path::get_pacdef_config_path;
+
+/// Settings read from the user's config file. All fields are optional in the file itself;
+/// [`Config::load`] fills in defaults for whatever is missing.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Config {
+    /// The AUR helper to invoke instead of `pacman` (e.g. `paru`, `yay`). Defaults to `pacman`.
+    #[serde(default = "default_aur_helper")]
+    pub aur_helper: String,
+    /// Extra arguments passed to the AUR helper when removing packages, if it requires any
+    /// beyond what `pacman` itself accepts.
+    #[serde(default)]
+    pub aur_rm_args: Option<Vec<String>>,
+    /// User-defined command aliases: maps a name not otherwise recognized as a subcommand to
+    /// the command line it should expand to, looked up by
+    /// [`crate::core::Pacdef::expand_alias`].
+    #[serde(default)]
+    pub aliases: HashMap<String, String>,
+}
+
+fn default_aur_helper() -> String {
+    "pacman".to_string()
+}
+
+impl Config {
+    /// Loads the config file, if one exists. A missing config file is not an error: it is
+    /// equivalent to a file containing no settings at all.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config file exists but cannot be read or parsed.
+    pub fn load() -> Result<Self> {
+        let path = get_pacdef_config_path().context("determining config file path")?;
+
+        if !path.exists() {
+            return Ok(Self {
+                aur_helper: default_aur_helper(),
+                ..Self::default()
+            });
+        }
+
+        let content = read_to_string(&path)
+            .with_context(|| format!("reading config file {}", path.display()))?;
+
+        toml::from_str(&content)
+            .with_context(|| format!("parsing config file {}", path.display()))
+    }
+}
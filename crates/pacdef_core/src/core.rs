@@ -2,6 +2,8 @@ use std::collections::HashSet;
 use std::fs::{remove_file, File};
 use std::os::unix::fs::symlink;
 use std::path::PathBuf;
+use std::sync::mpsc;
+use std::sync::Mutex;
 
 use anyhow::{anyhow, ensure, Context, Result};
 use clap::ArgMatches;
@@ -17,6 +19,7 @@ use crate::search;
 use crate::ui::get_user_confirmation;
 use crate::Config;
 use crate::Group;
+use crate::Package;
 
 /// Most data that is required during runtime of the program.
 pub struct Pacdef {
@@ -52,7 +55,10 @@ impl Pacdef {
     #[allow(clippy::unit_arg)]
     pub fn run_action_from_arg(mut self) -> Result<()> {
         match self.args.subcommand() {
-            Some((CLEAN, _)) => self.clean_packages(),
+            Some((CLEAN, args)) => {
+                let format = args.get_one::<args::Format>("format").copied();
+                self.clean_packages(format)
+            }
             Some((EDIT, args)) => self.edit_group_files(args).context("editing group files"),
             Some((GROUPS, _)) => Ok(self.show_groups()),
             Some((IMPORT, args)) => self.import_groups(args).context("importing groups"),
@@ -64,31 +70,75 @@ impl Pacdef {
             Some((SEARCH, args)) => {
                 search::search_packages(args, &self.groups).context("searching packages")
             }
-            Some((SYNC, _)) => self.install_packages(),
-            Some((UNMANAGED, _)) => self.show_unmanaged_packages(),
+            Some((SYNC, args)) => {
+                let format = args.get_one::<args::Format>("format").copied();
+                self.install_packages(format)
+            }
+            Some((UNMANAGED, args)) => {
+                let format = args.get_one::<args::Format>("format").copied();
+                self.show_unmanaged_packages(format)
+            }
             Some((VERSION, _)) => Ok(self.show_version()),
-            Some((_, _)) => panic!(),
+            Some((COMPLETION, args)) => Ok(self.generate_completions(args)),
+            Some((name, args)) => self
+                .expand_alias(name, args)
+                .with_context(|| format!("resolving alias '{name}'")),
             None => {
                 unreachable!("argument parser requires some subcommand to return an `ArgMatches`")
             }
         }
     }
 
-    fn get_missing_packages(&mut self) -> ToDoPerBackend {
-        let mut to_install = ToDoPerBackend::new();
-
-        for backend in Backends::iter() {
-            let mut backend = self.overwrite_values_from_config(backend);
-
-            backend.load(&self.groups);
-
-            match backend.get_missing_packages_sorted() {
-                Ok(diff) => to_install.push((backend, diff)),
-                Err(error) => show_error(&error, &*backend),
-            };
+    /// Resolves `name` as a user-defined alias from [`Config`] and re-dispatches.
+    ///
+    /// `name` reached here because it matched none of the built-in subcommands above,
+    /// so a built-in can never be shadowed by an alias. `args` holds whatever followed
+    /// `name` on the command line, collected by clap's external-subcommand support.
+    /// The expansion is looked up repeatedly so an alias may itself expand to another
+    /// alias, guarding against cycles by refusing to expand the same name twice.
+    fn expand_alias(mut self, name: &str, args: &ArgMatches) -> Result<()> {
+        let mut name = name.to_string();
+        let mut rest: Vec<std::ffi::OsString> = args
+            .get_many::<std::ffi::OsString>("")
+            .map(|values| values.cloned().collect())
+            .unwrap_or_default();
+
+        let mut seen = HashSet::new();
+        loop {
+            ensure!(seen.insert(name.clone()), "cyclic alias definition for '{name}'");
+
+            let expansion = self
+                .config
+                .aliases
+                .get(&name)
+                .cloned()
+                .ok_or_else(|| anyhow!("unrecognized subcommand '{name}'"))?;
+
+            let mut argv = vec![std::ffi::OsString::from("pacdef")];
+            argv.extend(expansion.split_whitespace().map(std::ffi::OsString::from));
+            argv.extend(rest);
+
+            self.args = args::command()
+                .try_get_matches_from(argv)
+                .with_context(|| format!("expanding alias '{name}' to '{expansion}'"))?;
+
+            match self.args.subcommand() {
+                Some((next_name, next_args))
+                    if args::command().find_subcommand(next_name).is_none() =>
+                {
+                    rest = next_args
+                        .get_many::<std::ffi::OsString>("")
+                        .map(|values| values.cloned().collect())
+                        .unwrap_or_default();
+                    name = next_name.to_string();
+                }
+                _ => return self.run_action_from_arg(),
+            }
         }
+    }
 
-        to_install
+    fn get_missing_packages(&mut self) -> ToDoPerBackend {
+        self.query_backends_parallel(|backend| backend.get_missing_packages_sorted())
     }
 
     fn overwrite_values_from_config(&mut self, backend: Box<dyn Backend>) -> Box<dyn Backend> {
@@ -103,9 +153,14 @@ impl Pacdef {
         }
     }
 
-    fn install_packages(&mut self) -> Result<()> {
+    fn install_packages(&mut self, format: Option<args::Format>) -> Result<()> {
+        let noconfirm = self.noconfirm();
         let to_install = self.get_missing_packages();
 
+        if let Some(format) = format {
+            return to_install.export(format).context("exporting plan");
+        }
+
         if to_install.nothing_to_do_for_all_backends() {
             println!("nothing to do");
             return Ok(());
@@ -115,11 +170,19 @@ impl Pacdef {
         to_install.show().context("printing things to do")?;
 
         println!();
-        if !get_user_confirmation()? {
+        if !noconfirm && !get_user_confirmation()? {
             return Ok(());
         };
 
-        to_install.install_missing_packages()
+        to_install.install_missing_packages(noconfirm)
+    }
+
+    /// Whether the user passed the global `--noconfirm` flag, which skips the
+    /// interactive confirmation prompt and is forwarded to each backend so it can
+    /// pass its own no-confirmation switch (e.g. pacman's `--noconfirm`) to the
+    /// underlying package manager.
+    fn noconfirm(&self) -> bool {
+        self.args.get_flag("noconfirm")
     }
 
     #[allow(clippy::unused_self)]
@@ -157,8 +220,20 @@ impl Pacdef {
         println!("{}", get_version_string());
     }
 
-    fn show_unmanaged_packages(mut self) -> Result<()> {
-        let unmanaged_per_backend = &self.get_unmanaged_packages();
+    #[allow(clippy::unused_self)]
+    fn generate_completions(self, args: &ArgMatches) {
+        let shell = *args
+            .get_one::<clap_complete::Shell>("shell")
+            .expect("shell is a required arg");
+        args::generate_completions(shell);
+    }
+
+    fn show_unmanaged_packages(mut self, format: Option<args::Format>) -> Result<()> {
+        let unmanaged_per_backend = self.get_unmanaged_packages();
+
+        if let Some(format) = format {
+            return unmanaged_per_backend.export(format).context("exporting plan");
+        }
 
         unmanaged_per_backend
             .show()
@@ -166,19 +241,59 @@ impl Pacdef {
     }
 
     fn get_unmanaged_packages(&mut self) -> ToDoPerBackend {
-        let mut result = ToDoPerBackend::new();
+        self.query_backends_parallel(|backend| backend.get_unmanaged_packages_sorted())
+    }
+
+    /// Runs `query` against every backend concurrently, one OS thread per backend, and
+    /// collects the results into a [`ToDoPerBackend`].
+    ///
+    /// Each backend's `load` and `query` calls spend most of their time blocked on a
+    /// subprocess (`pacman`, `rustup`, ...), so fanning them out hides their latencies
+    /// behind one another instead of paying for them one after another. While the
+    /// threads are running, a single status line naming the backends still in flight
+    /// is kept on screen; it is cleared before the first line of the resulting diff
+    /// is printed. Output order is made
+    /// deterministic again by sorting the joined results by backend section name,
+    /// since threads may finish in any order.
+    fn query_backends_parallel(
+        &mut self,
+        query: fn(&dyn Backend) -> anyhow::Result<Vec<Package>>,
+    ) -> ToDoPerBackend {
+        let backends: Vec<_> = Backends::iter()
+            .map(|backend| self.overwrite_values_from_config(backend))
+            .collect();
+
+        let progress = BackendProgress::new(backends.iter().map(|b| b.get_section()));
+        let (tx, rx) = mpsc::channel();
+
+        std::thread::scope(|scope| {
+            for mut backend in backends {
+                let tx = tx.clone();
+                let progress = &progress;
+                let groups = &self.groups;
+                scope.spawn(move || {
+                    backend.load(groups);
+                    let result = query(&*backend);
+                    progress.finish(backend.get_section());
+                    tx.send((backend, result)).expect("receiver is still alive");
+                });
+            }
+            drop(tx);
+        });
 
-        for backend in Backends::iter() {
-            let mut backend = self.overwrite_values_from_config(backend);
+        progress.clear();
 
-            backend.load(&self.groups);
+        let mut results: Vec<_> = rx.into_iter().collect();
+        results.sort_unstable_by(|(a, _), (b, _)| a.get_section().cmp(b.get_section()));
 
-            match backend.get_unmanaged_packages_sorted() {
-                Ok(unmanaged) => result.push((backend, unmanaged)),
+        let mut todo = ToDoPerBackend::new();
+        for (backend, result) in results {
+            match result {
+                Ok(diff) => todo.push((backend, diff)),
                 Err(error) => show_error(&error, &*backend),
-            };
+            }
         }
-        result
+        todo
     }
 
     fn show_groups(self) {
@@ -189,9 +304,14 @@ impl Pacdef {
         }
     }
 
-    fn clean_packages(mut self) -> Result<()> {
+    fn clean_packages(mut self, format: Option<args::Format>) -> Result<()> {
+        let noconfirm = self.noconfirm();
         let to_remove = self.get_unmanaged_packages();
 
+        if let Some(format) = format {
+            return to_remove.export(format).context("exporting plan");
+        }
+
         if to_remove.nothing_to_do_for_all_backends() {
             println!("nothing to do");
             return Ok(());
@@ -201,11 +321,11 @@ impl Pacdef {
         to_remove.show().context("printing things to do")?;
 
         println!();
-        if !get_user_confirmation()? {
+        if !noconfirm && !get_user_confirmation()? {
             return Ok(());
         };
 
-        to_remove.remove_unmanaged_packages()
+        to_remove.remove_unmanaged_packages(noconfirm)
     }
 
     fn show_group_content(&self, groups: &ArgMatches) -> Result<()> {
@@ -342,6 +462,41 @@ fn show_error(error: &anyhow::Error, backend: &dyn Backend) {
     }
 }
 
+/// A single status line on stderr, shown while backend queries are running
+/// concurrently, that lists the sections of the backends still in flight and is
+/// redrawn whenever one finishes. It is not animated, and all backends share the
+/// one line.
+struct BackendProgress {
+    pending: Mutex<Vec<String>>,
+}
+
+impl BackendProgress {
+    fn new<'a>(sections: impl Iterator<Item = &'a str>) -> Self {
+        let pending: Vec<_> = sections.map(str::to_string).collect();
+        let progress = Self {
+            pending: Mutex::new(pending),
+        };
+        progress.redraw();
+        progress
+    }
+
+    fn finish(&self, section: &str) {
+        let mut pending = self.pending.lock().expect("progress lock poisoned");
+        pending.retain(|s| s != section);
+        drop(pending);
+        self.redraw();
+    }
+
+    fn redraw(&self) {
+        let pending = self.pending.lock().expect("progress lock poisoned");
+        eprint!("\rquerying: {}\u{1b}[K", pending.join(", "));
+    }
+
+    fn clear(&self) {
+        eprint!("\r\u{1b}[K");
+    }
+}
+
 pub const fn get_version_string() -> &'static str {
     concat!(
         "pacdef, version: ",